@@ -196,6 +196,31 @@
 //!   .plugin(tauri_plugin_graphql::init(schema));
 //! ```
 //!
+//! ### File Uploads
+//!
+//! > **Support for file uploads requires the `upload` feature flag**
+//!
+//! Since Tauri IPC cannot carry an HTTP multipart body, the [`Upload`]
+//! scalar is instead fed through a dedicated `"upload"` command that accepts
+//! file contents either as a path on disk or as inline base64 bytes. See
+//! [`UploadRequest`] for the accepted shape.
+//!
+//! [`Upload`]: https://docs.rs/async-graphql/latest/async_graphql/struct.Upload.html
+//!
+//! ### Schema Export & Codegen
+//!
+//! Since both the Rust and JavaScript side of the interface can be generated
+//! from a common schema, [`Builder::export_schema`] writes the schema's SDL
+//! to disk during plugin setup so frontend codegen tools (`graphql-codegen`,
+//! `typed-document-node`) have a static file to generate from. The SDL is
+//! also available at runtime as the managed [`SchemaSdl`] state.
+//!
+//! > **The `"introspect"` command requires the `dev` feature flag**
+//!
+//! Behind the `dev` feature, the plugin also registers an `"introspect"`
+//! command that runs the standard `__schema` introspection query, letting
+//! tooling introspect the schema directly over IPC.
+//!
 //! ## Stability
 //!
 //! To work around limitations with the current command system, this plugin
@@ -210,16 +235,495 @@
 //! [`Events`]: https://tauri.studio/docs/guides/events
 //! [`GraphQL`]: https://graphql.org
 
-use std::sync::Arc;
+use std::{
+  collections::HashMap,
+  sync::{Arc, Mutex},
+};
 
 use async_graphql::{
-  futures_util::StreamExt, BatchRequest, ObjectType, Request, Schema, SubscriptionType,
+  futures_util::StreamExt, BatchRequest, Data, ExtensionFactory, ObjectType, Request, Schema,
+  SchemaBuilder, SubscriptionType,
 };
-use serde::Deserialize;
+#[cfg(feature = "upload")]
+use async_graphql::UploadValue;
+use serde::{Deserialize, Serialize};
 use tauri::{
   plugin::{self, TauriPlugin},
-  InvokeError, Manager, Runtime,
+  InvokeError, Manager, Runtime, Window,
 };
+use tokio::sync::oneshot;
+
+/// Tracks in-flight subscriptions so that they can be cancelled by the
+/// frontend via the `"stop_subscription"` command.
+///
+/// Managed as Tauri state by [`Builder::build`], keyed by the window label
+/// and the subscription `id` the frontend chose when it issued the
+/// `"subscriptions"` invoke. The window label is part of the key because
+/// `id` is chosen by the frontend and is only unique within a single
+/// window; without it, two windows picking the same `id` (or one window
+/// reusing an `id` while the last subscription is still tearing down) would
+/// silently cancel each other's stream.
+#[derive(Default)]
+struct SubscriptionRegistry {
+  stop_senders: Mutex<HashMap<(String, u32), oneshot::Sender<()>>>,
+}
+
+/// A single step of a subscription stream, emitted on `graphql://{id}`.
+///
+/// Borrows the `next`/`error`/`complete` framing from the
+/// `graphql-transport-ws` protocol so the frontend gets a discriminated
+/// union instead of having to guess whether a `null` event means "done" or
+/// "no data".
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", content = "payload", rename_all = "snake_case")]
+enum SubscriptionMessage {
+  /// A normal resolved payload.
+  Next(serde_json::Value),
+  /// The GraphQL errors for a stream item that resolved to errors, or the
+  /// error encountered while serializing one that didn't.
+  Error(Vec<serde_json::Value>),
+  /// Sent exactly once, after the stream ends or is cancelled.
+  Complete,
+}
+
+/// Converts a subscription stream item into a [`SubscriptionMessage`],
+/// surfacing GraphQL errors instead of silently dropping them.
+fn subscription_message(response: async_graphql::Response) -> SubscriptionMessage {
+  if !response.is_ok() {
+    return SubscriptionMessage::Error(
+      response
+        .errors
+        .iter()
+        .map(|err| serde_json::to_value(err).unwrap_or(serde_json::Value::Null))
+        .collect(),
+    );
+  }
+
+  match serde_json::to_value(&response.data) {
+    Ok(value) => SubscriptionMessage::Next(value),
+    Err(err) => {
+      SubscriptionMessage::Error(vec![serde_json::json!({ "message": err.to_string() })])
+    }
+  }
+}
+
+/// A function that contributes a piece of [`Data`] to the context of every
+/// request, based on the [`Window`] the request originated from.
+///
+/// See [`Builder::data`].
+type DataFn<R> = Box<dyn Fn(&Window<R>) -> Data + Send + Sync>;
+
+/// A `connection_init`-style hook validating the optional `payload` sent
+/// alongside a `"graphql"` or `"subscriptions"` invoke.
+///
+/// See [`Builder::on_connection_init`].
+type ConnectionInitHook =
+  Mutex<Box<dyn FnMut(serde_json::Value) -> async_graphql::Result<Data> + Send>>;
+
+/// Builds a GraphQL plugin, allowing additional context `Data` and
+/// `async_graphql` extensions to be registered before the plugin is built.
+///
+/// This is the extensible counterpart to [`init`], which only covers the
+/// common case of a bare schema with no additional context or extensions.
+///
+/// ## Example
+///
+/// ```rust
+/// use async_graphql::{Schema, EmptyMutation, EmptySubscription, extensions::ApolloTracing};
+/// # use async_graphql::{Object, Result as GraphQLResult};
+/// # struct Query;
+/// # #[Object]
+/// # impl Query {
+/// #   async fn noop(&self) -> GraphQLResult<bool> { Ok(true) }
+/// # }
+///
+/// #[derive(Clone)]
+/// struct Pool;
+///
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription);
+///
+/// tauri::Builder::default()
+///     .plugin(
+///         tauri_plugin_graphql::Builder::new(schema)
+///             .data(Pool)
+///             .extension(ApolloTracing)
+///             .build(),
+///     );
+/// ```
+pub struct Builder<R, Query, Mutation, Subscription>
+where
+  R: Runtime,
+{
+  schema: SchemaBuilder<Query, Mutation, Subscription>,
+  data_fns: Vec<DataFn<R>>,
+  on_connection_init: Option<ConnectionInitHook>,
+  export_schema_path: Option<std::path::PathBuf>,
+}
+
+impl<R, Query, Mutation, Subscription> Builder<R, Query, Mutation, Subscription>
+where
+  R: Runtime,
+  Query: ObjectType + 'static,
+  Mutation: ObjectType + 'static,
+  Subscription: SubscriptionType + 'static,
+{
+  /// Creates a new builder wrapping the given schema.
+  ///
+  /// `schema` is a [`SchemaBuilder`] (the result of [`Schema::build`]) rather
+  /// than an already-[`finish`](SchemaBuilder::finish)ed [`Schema`], so that
+  /// [`Builder::extension`] has a chance to register extensions before the
+  /// schema is built.
+  pub fn new(schema: SchemaBuilder<Query, Mutation, Subscription>) -> Self {
+    Self {
+      schema,
+      data_fns: Vec::new(),
+      on_connection_init: None,
+      export_schema_path: None,
+    }
+  }
+
+  /// Registers a value to be inserted into the [`Context`](async_graphql::Context)
+  /// of every request, in addition to the [`AppHandle`](tauri::AppHandle) and
+  /// [`Window`] that are always present.
+  ///
+  /// Use [`Builder::data_fn`] instead if the value depends on the `Window`
+  /// the request originated from (e.g. per-window state).
+  pub fn data<D: Clone + Send + Sync + 'static>(mut self, data: D) -> Self {
+    self.data_fns.push(Box::new(move |_window| {
+      let mut d = Data::default();
+      d.insert(data.clone());
+      d
+    }));
+    self
+  }
+
+  /// Registers a closure that produces [`Data`] for every request, given the
+  /// [`Window`] it originated from.
+  pub fn data_fn<F>(mut self, f: F) -> Self
+  where
+    F: Fn(&Window<R>) -> Data + Send + Sync + 'static,
+  {
+    self.data_fns.push(Box::new(f));
+    self
+  }
+
+  /// Registers an `async_graphql` [`ExtensionFactory`] (e.g. tracing, Apollo
+  /// tracing, query-complexity limits) with the schema.
+  pub fn extension<E: ExtensionFactory>(mut self, extension: E) -> Self {
+    self.schema = self.schema.extension(extension);
+    self
+  }
+
+  /// Registers a `connection_init`-style hook, following the
+  /// `graphql-transport-ws` handshake model.
+  ///
+  /// If the frontend sends an optional `payload` alongside a `"graphql"` or
+  /// `"subscriptions"` invoke, `hook` is run with that payload before the
+  /// request executes. Returning `Ok(data)` merges `data` into the request's
+  /// context, so resolvers can read it back out (e.g. `ctx.data::<Claims>()`).
+  /// Returning `Err` rejects the invoke with that error instead of running
+  /// the request, giving the plugin a single place to enforce
+  /// authentication/authorization.
+  pub fn on_connection_init<F>(mut self, hook: F) -> Self
+  where
+    F: FnMut(serde_json::Value) -> async_graphql::Result<Data> + Send + 'static,
+  {
+    self.on_connection_init = Some(Mutex::new(Box::new(hook)));
+    self
+  }
+
+  /// Writes the schema's SDL to `path` during plugin setup.
+  ///
+  /// This closes the loop on generating both the Rust and JavaScript side of
+  /// the interface from a common schema: point `graphql-codegen` or
+  /// `typed-document-node` at `path` to generate typed frontend bindings.
+  pub fn export_schema(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+    self.export_schema_path = Some(path.into());
+    self
+  }
+
+  /// Builds the plugin, finishing the schema (applying any registered
+  /// extensions) before wiring up the invoke handler.
+  pub fn build(self) -> TauriPlugin<R> {
+    build_plugin(
+      self.schema.finish(),
+      self.data_fns,
+      self.on_connection_init,
+      self.export_schema_path,
+    )
+  }
+}
+
+/// Shared by [`Builder::build`] and [`init`]: wires up the invoke handler for
+/// an already-finished [`Schema`].
+fn build_plugin<R, Query, Mutation, Subscription>(
+  schema: Schema<Query, Mutation, Subscription>,
+  data_fns: Vec<DataFn<R>>,
+  on_connection_init: Option<ConnectionInitHook>,
+  export_schema_path: Option<std::path::PathBuf>,
+) -> TauriPlugin<R>
+where
+  R: Runtime,
+  Query: ObjectType + 'static,
+  Mutation: ObjectType + 'static,
+  Subscription: SubscriptionType + 'static,
+{
+  let sdl = schema.sdl();
+
+  let schema = Arc::new(schema);
+  let data_fns = Arc::new(data_fns);
+  let on_connection_init = Arc::new(on_connection_init);
+
+  plugin::Builder::new("graphql")
+    .setup(move |app_handle| {
+      app_handle.manage(SubscriptionRegistry::default());
+      app_handle.manage(SchemaSdl(sdl.clone()));
+
+      if let Some(path) = &export_schema_path {
+        std::fs::write(path, &sdl)?;
+      }
+
+      Ok(())
+    })
+    .invoke_handler(move |invoke| {
+      let window = invoke.message.window();
+
+      let schema = schema.clone();
+      let data_fns = data_fns.clone();
+      let on_connection_init = on_connection_init.clone();
+
+      match invoke.message.command() {
+        "graphql" => invoke.resolver.respond_async(async move {
+          let mut payload = invoke.message.payload().clone();
+          let connection_payload = take_connection_payload(&mut payload);
+
+          let req: BatchRequest =
+            serde_json::from_value(payload).map_err(InvokeError::from_serde_json)?;
+
+          let req = req.data(window.app_handle()).data(window.clone());
+          let req = apply_data_fns(req, &window, &data_fns);
+          let req =
+            apply_connection_init(req, &on_connection_init, connection_payload.as_ref())?;
+
+          let resp = schema.execute_batch(req).await;
+
+          let str = serde_json::to_string(&resp).map_err(InvokeError::from_serde_json)?;
+
+          Ok((str, resp.is_ok()))
+        }),
+        "subscriptions" => invoke.resolver.respond_async(async move {
+          let mut payload = invoke.message.payload().clone();
+          let connection_payload = take_connection_payload(&mut payload);
+
+          let req: SubscriptionRequest =
+            serde_json::from_value(payload).map_err(InvokeError::from_serde_json)?;
+
+          let subscription_window = window.clone();
+
+          let mut inner = req.inner.data(window.app_handle()).data(window.clone());
+          for f in data_fns.iter() {
+            inner.data.merge(f(&window));
+          }
+          if let (Some(hook), Some(payload)) = (&*on_connection_init, &connection_payload) {
+            let data = (hook.lock().unwrap())(payload.clone())
+              .map_err(|err| InvokeError::from(err.message))?;
+            inner.data.merge(data);
+          }
+
+          let mut stream = schema.execute_stream(inner);
+
+          let event_id = &format!("graphql://{}", req.id);
+
+          let (stop_tx, mut stop_rx) = oneshot::channel();
+          window
+            .state::<SubscriptionRegistry>()
+            .stop_senders
+            .lock()
+            .unwrap()
+            .insert((subscription_window.label().to_string(), req.id), stop_tx);
+
+          loop {
+            tokio::select! {
+              result = stream.next() => {
+                match result {
+                  Some(response) => {
+                    let message = subscription_message(response);
+                    let str = serde_json::to_string(&message).map_err(InvokeError::from_serde_json)?;
+                    subscription_window.emit(event_id, str)?;
+                  }
+                  None => break,
+                }
+              }
+              _ = &mut stop_rx => break,
+            }
+          }
+
+          // Dropping our receiver makes our own sender report `is_closed`, so
+          // the entry is only removed while it is still ours and not that of a
+          // newer subscription which reused the same key.
+          drop(stop_rx);
+          {
+            let registry = window.state::<SubscriptionRegistry>();
+            let mut stop_senders = registry.stop_senders.lock().unwrap();
+            let key = (subscription_window.label().to_string(), req.id);
+            if stop_senders.get(&key).map_or(false, |tx| tx.is_closed()) {
+              stop_senders.remove(&key);
+            }
+          }
+
+          let complete = serde_json::to_string(&SubscriptionMessage::Complete)
+            .map_err(InvokeError::from_serde_json)?;
+          subscription_window.emit(event_id, complete)?;
+
+          Ok(())
+        }),
+        "stop_subscription" => invoke.resolver.respond_async(async move {
+          let req: StopSubscriptionRequest =
+            serde_json::from_value(invoke.message.payload().clone())
+              .map_err(InvokeError::from_serde_json)?;
+
+          if let Some(stop_tx) = window
+            .state::<SubscriptionRegistry>()
+            .stop_senders
+            .lock()
+            .unwrap()
+            .remove(&(window.label().to_string(), req.id))
+          {
+            let _ = stop_tx.send(());
+          }
+
+          Ok(())
+        }),
+        #[cfg(feature = "upload")]
+        "upload" => invoke.resolver.respond_async(async move {
+          let mut payload = invoke.message.payload().clone();
+          let connection_payload = take_connection_payload(&mut payload);
+
+          let req: UploadRequest =
+            serde_json::from_value(payload).map_err(InvokeError::from_serde_json)?;
+
+          let mut request = Request::new(req.query)
+            .variables(req.variables)
+            .data(window.app_handle())
+            .data(window.clone());
+          for f in data_fns.iter() {
+            request.data.merge(f(&window));
+          }
+          if let (Some(hook), Some(payload)) = (&*on_connection_init, &connection_payload) {
+            let data = (hook.lock().unwrap())(payload.clone())
+              .map_err(|err| InvokeError::from(err.message))?;
+            request.data.merge(data);
+          }
+
+          for (key, paths) in &req.map {
+            let source = req.files.get(key).ok_or_else(|| {
+              InvokeError::from(format!("no file provided for map key \"{}\"", key))
+            })?;
+
+            for path in paths {
+              let upload = build_upload_value(source.clone(), &window)?;
+              request.set_upload(path, upload);
+            }
+          }
+
+          let resp = schema.execute(request).await;
+
+          let str = serde_json::to_string(&resp).map_err(InvokeError::from_serde_json)?;
+
+          Ok((str, resp.is_ok()))
+        }),
+        #[cfg(feature = "dev")]
+        "introspect" => invoke.resolver.respond_async(async move {
+          let resp = schema.execute(INTROSPECTION_QUERY).await;
+
+          let str = serde_json::to_string(&resp).map_err(InvokeError::from_serde_json)?;
+
+          Ok((str, resp.is_ok()))
+        }),
+        cmd => {
+          // `mut` is only exercised by the `push`es below, which are themselves
+          // cfg-gated, so it goes unused with the default feature set.
+          #[cfg_attr(not(any(feature = "upload", feature = "dev")), allow(unused_mut))]
+          let mut valid_endpoints =
+            vec!["\"graphql\"", "\"subscriptions\"", "\"stop_subscription\""];
+          #[cfg(feature = "upload")]
+          valid_endpoints.push("\"upload\"");
+          #[cfg(feature = "dev")]
+          valid_endpoints.push("\"introspect\"");
+
+          invoke.resolver.reject(format!(
+            "Invalid endpoint \"{}\". Valid endpoints are: {}.",
+            cmd,
+            valid_endpoints.join(", ")
+          ))
+        }
+      }
+    })
+    .build()
+}
+
+/// Applies every registered [`DataFn`] to a [`BatchRequest`], covering both
+/// the single-request and batched-requests case.
+fn apply_data_fns<R: Runtime>(
+  req: BatchRequest,
+  window: &Window<R>,
+  data_fns: &[DataFn<R>],
+) -> BatchRequest {
+  match req {
+    BatchRequest::Single(mut req) => {
+      for f in data_fns {
+        req.data.merge(f(window));
+      }
+      BatchRequest::Single(req)
+    }
+    BatchRequest::Batch(reqs) => BatchRequest::Batch(
+      reqs
+        .into_iter()
+        .map(|mut req| {
+          for f in data_fns {
+            req.data.merge(f(window));
+          }
+          req
+        })
+        .collect(),
+    ),
+  }
+}
+
+/// Pulls the optional top-level `payload` field (the `connection_init`-style
+/// auth payload) out of an invoke's raw JSON before it is deserialized into a
+/// [`BatchRequest`] or [`SubscriptionRequest`], neither of which know about it.
+fn take_connection_payload(value: &mut serde_json::Value) -> Option<serde_json::Value> {
+  value.as_object_mut().and_then(|obj| obj.remove("payload"))
+}
+
+/// Runs the [`Builder::on_connection_init`] hook (if one is registered and a
+/// `payload` was sent) and merges its [`Data`] into every request in the
+/// batch, rejecting the whole invoke if the hook returns an error.
+fn apply_connection_init(
+  req: BatchRequest,
+  hook: &Option<ConnectionInitHook>,
+  payload: Option<&serde_json::Value>,
+) -> Result<BatchRequest, InvokeError> {
+  let (hook, payload) = match (hook, payload) {
+    (Some(hook), Some(payload)) => (hook, payload),
+    _ => return Ok(req),
+  };
+
+  let merge_one = |mut req: Request| -> Result<Request, InvokeError> {
+    let data =
+      (hook.lock().unwrap())(payload.clone()).map_err(|err| InvokeError::from(err.message))?;
+    req.data.merge(data);
+    Ok(req)
+  };
+
+  match req {
+    BatchRequest::Single(req) => Ok(BatchRequest::Single(merge_one(req)?)),
+    BatchRequest::Batch(reqs) => Ok(BatchRequest::Batch(
+      reqs.into_iter().map(merge_one).collect::<Result<_, _>>()?,
+    )),
+  }
+}
 
 /// Initializes the GraphQL plugin
 ///
@@ -229,6 +733,12 @@ use tauri::{
 ///
 /// The `schema` argument must be a valid [`juniper::RootNode`].
 ///
+/// This is a thin wrapper around [`Builder`] for the common case where no
+/// additional context data or extensions are needed. Use [`Builder`] directly
+/// to inject database pools, config, auth state or `async_graphql`
+/// extensions (tracing, Apollo tracing, query-complexity limits) into every
+/// request's context.
+///
 /// ## Example
 ///
 /// ```rust
@@ -271,57 +781,212 @@ where
   Mutation: ObjectType + 'static,
   Subscription: SubscriptionType + 'static,
 {
-  let schema = Arc::new(schema);
+  build_plugin(schema, Vec::new(), None, None)
+}
 
-  plugin::Builder::new("graphql")
-    .invoke_handler(move |invoke| {
-      let window = invoke.message.window();
+#[derive(Debug, Deserialize)]
+pub struct SubscriptionRequest {
+  #[serde(flatten)]
+  inner: Request,
+  id: u32,
+}
 
-      let schema = schema.clone();
+#[derive(Debug, Deserialize)]
+pub struct StopSubscriptionRequest {
+  id: u32,
+}
 
-      match invoke.message.command() {
-        "graphql" => invoke.resolver.respond_async(async move {
-          let req: BatchRequest = serde_json::from_value(invoke.message.payload().clone())
-            .map_err(InvokeError::from_serde_json)?;
+/// The request shape accepted by the `"upload"` command, mirroring the
+/// `operations`/`map`/file-parts shape of the [GraphQL multipart request
+/// spec](https://github.com/jaydenseric/graphql-multipart-request-spec),
+/// adapted to Tauri IPC since it cannot carry an HTTP multipart body.
+///
+/// `map` links a file key (matching a key in `files`) to the variable paths
+/// (e.g. `"variables.file"`) that should receive it, exactly like the `map`
+/// field in the multipart spec.
+#[cfg(feature = "upload")]
+#[derive(Debug, Deserialize)]
+pub struct UploadRequest {
+  query: String,
+  #[serde(default)]
+  variables: async_graphql::Variables,
+  map: HashMap<String, Vec<String>>,
+  files: HashMap<String, UploadSource>,
+}
 
-          let resp = schema
-            .execute_batch(req.data(window.app_handle()).data(window))
-            .await;
+/// Where to read an uploaded file's bytes from, since Tauri IPC can't stream
+/// a multipart file part the way an HTTP integration would.
+#[cfg(feature = "upload")]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum UploadSource {
+  /// A path on disk, subject to Tauri's fs scope.
+  Path { path: std::path::PathBuf },
+  /// Base64-encoded bytes sent inline over IPC.
+  Inline {
+    name: String,
+    #[serde(default)]
+    mime: Option<String>,
+    bytes: String,
+  },
+}
 
-          let str = serde_json::to_string(&resp).map_err(InvokeError::from_serde_json)?;
+/// Builds an [`UploadValue`] from an [`UploadSource`], opening the file on
+/// disk or decoding inline bytes into a temporary file as needed.
+///
+/// [`UploadSource::Path`] is checked against `window`'s [fs
+/// scope](https://tauri.app/v1/api/config/#fsallowlistconfig.scope) before
+/// being opened, so a frontend can't read arbitrary files off disk by naming
+/// them as an upload.
+#[cfg(feature = "upload")]
+fn build_upload_value<R: Runtime>(
+  source: UploadSource,
+  window: &Window<R>,
+) -> Result<UploadValue, InvokeError> {
+  use base64::Engine;
+  use std::io::{Seek, SeekFrom, Write};
 
-          Ok((str, resp.is_ok()))
-        }),
-        "subscriptions" => invoke.resolver.respond_async(async move {
-          let req: SubscriptionRequest = serde_json::from_value(invoke.message.payload().clone())
-            .map_err(InvokeError::from_serde_json)?;
+  match source {
+    UploadSource::Path { path } => {
+      if !window.fs_scope().is_allowed(&path) {
+        return Err(InvokeError::from(format!(
+          "path \"{}\" is not allowed by the filesystem scope",
+          path.display()
+        )));
+      }
 
-          let subscription_window = window.clone();
-          let mut stream = schema.execute_stream(req.inner.data(window.app_handle()).data(window));
+      let filename = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+      let content_type = mime_guess::from_path(&path)
+        .first()
+        .map(|mime| mime.to_string());
+      let content =
+        std::fs::File::open(&path).map_err(|err| InvokeError::from(err.to_string()))?;
 
-          let event_id = &format!("graphql://{}", req.id);
+      Ok(UploadValue {
+        filename,
+        content_type,
+        content,
+      })
+    }
+    UploadSource::Inline { name, mime, bytes } => {
+      let bytes = base64::engine::general_purpose::STANDARD
+        .decode(bytes)
+        .map_err(|err| InvokeError::from(err.to_string()))?;
 
-          while let Some(result) = stream.next().await {
-            let str = serde_json::to_string(&result).map_err(InvokeError::from_serde_json)?;
+      let mut content = tempfile::tempfile().map_err(|err| InvokeError::from(err.to_string()))?;
+      content
+        .write_all(&bytes)
+        .map_err(|err| InvokeError::from(err.to_string()))?;
+      content
+        .seek(SeekFrom::Start(0))
+        .map_err(|err| InvokeError::from(err.to_string()))?;
 
-            subscription_window.emit(event_id, str)?;
-          }
-          subscription_window.emit(event_id, Option::<()>::None)?;
+      Ok(UploadValue {
+        filename: name,
+        content_type: mime,
+        content,
+      })
+    }
+  }
+}
 
-          Ok(())
-        }),
-        cmd => invoke.resolver.reject(format!(
-          "Invalid endpoint \"{}\". Valid endpoints are: \"graphql\", \"subscriptions\".",
-          cmd
-        )),
-      }
-    })
-    .build()
+/// The schema's SDL, managed as Tauri state by [`Builder::build`].
+///
+/// Lets other commands, a custom HTTP endpoint, or tooling read the schema
+/// frontend codegen tools (`graphql-codegen`, `typed-document-node`) can
+/// generate from, without holding a reference to the schema itself.
+pub struct SchemaSdl(String);
+
+impl SchemaSdl {
+  /// The schema rendered as GraphQL SDL.
+  pub fn schema_sdl(&self) -> &str {
+    &self.0
+  }
 }
 
-#[derive(Debug, Deserialize)]
-pub struct SubscriptionRequest {
-  #[serde(flatten)]
-  inner: Request,
-  id: u32,
-}
\ No newline at end of file
+/// The standard `__schema` introspection query, run by the `"introspect"`
+/// command (behind the `dev` feature) so codegen tooling can query the
+/// schema over IPC without constructing its own introspection document.
+#[cfg(feature = "dev")]
+const INTROSPECTION_QUERY: &str = r#"
+  query IntrospectionQuery {
+    __schema {
+      queryType { name }
+      mutationType { name }
+      subscriptionType { name }
+      types { ...FullType }
+      directives {
+        name
+        description
+        locations
+        args { ...InputValue }
+      }
+    }
+  }
+
+  fragment FullType on __Type {
+    kind
+    name
+    description
+    fields(includeDeprecated: true) {
+      name
+      description
+      args { ...InputValue }
+      type { ...TypeRef }
+      isDeprecated
+      deprecationReason
+    }
+    inputFields { ...InputValue }
+    interfaces { ...TypeRef }
+    enumValues(includeDeprecated: true) {
+      name
+      description
+      isDeprecated
+      deprecationReason
+    }
+    possibleTypes { ...TypeRef }
+  }
+
+  fragment InputValue on __InputValue {
+    name
+    description
+    type { ...TypeRef }
+    defaultValue
+  }
+
+  fragment TypeRef on __Type {
+    kind
+    name
+    ofType {
+      kind
+      name
+      ofType {
+        kind
+        name
+        ofType {
+          kind
+          name
+          ofType {
+            kind
+            name
+            ofType {
+              kind
+              name
+              ofType {
+                kind
+                name
+                ofType {
+                  kind
+                  name
+                }
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+"#;